@@ -57,18 +57,16 @@ fn main() -> Result<()> {
         .append(true)
         .open(output_csv)?;
 
-    let mut writer = csv::WriterBuilder::new()
+    let writer = csv::WriterBuilder::new()
         .has_headers(!should_append)
         .from_writer(BufWriter::new(file));
 
     // 4. Run Benchmark
-    let iterations = 1;
-    println!("Running benchmark with {} iterations...", iterations);
+    println!("Running benchmark with adaptive sampling...");
     let pb = ProgressBar::new((pairs.len() * decoders.len()) as u64);
 
-    benchmark::run_benchmark(&decoders, &pairs, iterations, &mut writer, &pb)?;
+    benchmark::run_benchmark(&decoders, &pairs, writer, &pb, 1)?;
     pb.finish_with_message("Benchmark complete");
-    writer.flush()?;
 
     println!("Benchmark finished. Data saved to {}.", output_csv);
 
@@ -1,9 +1,13 @@
 use crate::data::TestPair;
 use crate::decoders::QrDecoder;
 use anyhow::Result;
+use crossbeam_queue::ArrayQueue;
 use indicatif::ProgressBar;
 use serde::Serialize;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Serialize)]
 pub struct Measurement {
@@ -15,17 +19,90 @@ pub struct Measurement {
     pub status: String,
     pub expected_text: String,
     pub decoded_text: String,
+    // Best-matching ground-truth quad IoU, when expected_points are
+    // available for this pair. Empty for text-only pairs.
+    pub iou: Option<f32>,
+    // Whether the adaptive sampling loop hit its relative-precision target
+    // for this (decoder, image) job before running out of the iteration or
+    // wall-clock budget. `false` means the job took `ADAPTIVE_MAX_ITERATIONS`
+    // samples (or however many fit in `ADAPTIVE_MAX_DURATION`) and the
+    // measurement should be treated as noisier than the others.
+    pub converged: bool,
 }
 
-// Calculate intersection over union for polygons if possible, 
-// or just average corner distance error.
-// Since ordering of points might differ or be rotated, we need to be careful.
-// A simpler metric for "Correct" detection:
-// Average distance between matched corners is less than a threshold (e.g. 5% of image size or fixed pixels).
+// How long to spend on untimed warmup decodes before measuring, so the
+// first few measured iterations aren't skewed by one-time costs (allocator
+// warmup, lazy static init, page faults) the way a fixed one-shot warmup
+// decode can be on a slow first image.
+const WARMUP_DURATION: Duration = Duration::from_millis(100);
+
+// Always take at least this many measured iterations before checking for
+// convergence; a bootstrap CI on fewer samples is too noisy to trust.
+const ADAPTIVE_MIN_ITERATIONS: u32 = 10;
+
+// Give up and report the job unconverged after this many measured
+// iterations, so a decoder/image pair with truly unstable timings (e.g.
+// thrashing, contention) doesn't run forever.
+const ADAPTIVE_MAX_ITERATIONS: u32 = 500;
+
+// ... or after this much wall-clock time measuring, whichever comes first.
+const ADAPTIVE_MAX_DURATION: Duration = Duration::from_secs(5);
+
+// Re-check convergence every this-many measured iterations; checking every
+// single iteration would mean paying for a bootstrap resample every time.
+const ADAPTIVE_CHECK_INTERVAL: u32 = 10;
+
+// Convergence target: the 95% bootstrap CI half-width for the median must
+// drop under this fraction of the median itself, i.e. "the median is known
+// to within +/-2%".
+const ADAPTIVE_TARGET_RELATIVE_HALF_WIDTH: f64 = 0.02;
+
+// Resamples used for the periodic convergence check. Much cheaper than
+// `stats`'s default reporting-time bootstrap, since this runs many times
+// per job instead of once per group.
+const ADAPTIVE_CHECK_RESAMPLES: usize = 500;
+
+// Default IoU threshold above which a detected quad counts as a correct
+// detection of a ground-truth quad.
+const DETECTION_IOU_THRESHOLD: f32 = 0.5;
+
+/// Fallback detection check for quads `quad_iou` reports as degenerate
+/// (zero-area or self-intersecting input, not merely zero overlap):
+/// average corner distance under a fixed pixel tolerance, tried at all 4
+/// rotations since we don't know which corner the detector started at.
+fn corner_distance_correct(
+    expected_points: &[(f32, f32)],
+    actual_points: &[(f32, f32)],
+    tolerance: f32,
+) -> bool {
+    let mut min_avg_dist = f32::MAX;
+
+    for offset in 0..4 {
+        let mut total_dist = 0.0;
+        for i in 0..4 {
+            let p1 = expected_points[i];
+            let p2 = actual_points[(i + offset) % 4];
+            let dist = ((p1.0 - p2.0).powi(2) + (p1.1 - p2.1).powi(2)).sqrt();
+            total_dist += dist;
+        }
+        let avg_dist = total_dist / 4.0;
+        if avg_dist < min_avg_dist {
+            min_avg_dist = avg_dist;
+        }
+    }
+
+    min_avg_dist < tolerance
+}
+
+/// A detection counts as correct when its quad overlaps a ground-truth
+/// quad by at least `iou_threshold` Intersection-over-Union, which is
+/// scale-invariant (unlike a fixed pixel tolerance) and rotation-invariant
+/// by construction (polygon area doesn't care which corner a quad starts
+/// at, unlike the corner-distance check it replaces).
 fn is_detection_correct(
-    expected_points_sets: &[Vec<(f32, f32)>], 
+    expected_points_sets: &[Vec<(f32, f32)>],
     actual_points: &[(f32, f32)],
-    tolerance: f32
+    iou_threshold: f32,
 ) -> bool {
     if actual_points.len() != 4 {
         return false;
@@ -33,109 +110,275 @@ fn is_detection_correct(
 
     // Check against ALL expected sets. If it matches ANY set, it's a success.
     for expected_points in expected_points_sets {
-         if expected_points.len() != 4 {
-             continue; 
-         }
-
-        // We don't know the starting corner index for sure (rotation).
-        // Try all 4 rotations for the actual points to find best match.
-        let mut min_avg_dist = f32::MAX;
-
-        for offset in 0..4 {
-            let mut total_dist = 0.0;
-            for i in 0..4 {
-                let p1 = expected_points[i];
-                let p2 = actual_points[(i + offset) % 4];
-                let dist = ((p1.0 - p2.0).powi(2) + (p1.1 - p2.1).powi(2)).sqrt();
-                total_dist += dist;
-            }
-            let avg_dist = total_dist / 4.0;
-            if avg_dist < min_avg_dist {
-                min_avg_dist = avg_dist;
-            }
+        if expected_points.len() != 4 {
+            continue;
         }
-        
-        if min_avg_dist < tolerance {
-            return true;
+
+        match crate::scoring::quad_iou(expected_points, actual_points) {
+            Some(iou) if iou >= iou_threshold => return true,
+            Some(_) => continue,
+            None => {
+                // Degenerate input (zero-area / self-intersecting quad),
+                // not a well-formed quad with no overlap -- fall back to
+                // the old corner-distance check rather than silently
+                // treating a malformed quad as a miss.
+                if corner_distance_correct(expected_points, actual_points, 50.0) {
+                    return true;
+                }
+            }
         }
     }
 
     false
 }
 
-pub fn run_benchmark<W: std::io::Write>(
+fn score_result(
+    pair: &TestPair,
+    decode_result: &crate::decoders::DecodeResult,
+) -> (String, String, Option<f32>) {
+    let mut status = "Incorrect".to_string();
+    let text = decode_result.text.clone();
+    let mut iou = None;
+
+    // 1. Text Comparison (if expected text is available)
+    if let Some(expected_text) = &pair.expected_text {
+        let normalized_expected = expected_text.replace("\r\n", "\n").trim().to_string();
+        let normalized_decoded = text.replace("\r\n", "\n").trim().to_string();
+
+        if normalized_decoded == normalized_expected {
+            status = "Correct".to_string();
+        }
+    }
+    // 2. Point/Detection Comparison (if expected points are available)
+    else if let Some(expected_points_sets) = &pair.expected_points {
+        if let Some(actual_points) = &decode_result.points {
+            if is_detection_correct(expected_points_sets, actual_points, DETECTION_IOU_THRESHOLD) {
+                status = "Correct".to_string();
+            }
+
+            let detected = vec![actual_points.clone()];
+            let score = crate::scoring::match_detections(expected_points_sets, &detected);
+            iou = Some(score.best_iou());
+        } else {
+            status = "NoPoints".to_string();
+        }
+    }
+
+    (status, text, iou)
+}
+
+// One unit of work: adaptively sample decoder `decoder_idx` against image
+// `pair_idx` until the measured durations converge (or a budget runs out),
+// then emit one `Measurement` per measured iteration.
+struct WorkItem {
+    decoder_idx: usize,
+    pair_idx: usize,
+}
+
+/// Time untimed warmup decodes for `WARMUP_DURATION` so lazy static init,
+/// allocator warmup, and page faults from the first few calls don't bleed
+/// into the measured samples.
+fn warm_up(decoder: &dyn QrDecoder, img: &image::DynamicImage) {
+    let start = Instant::now();
+    while start.elapsed() < WARMUP_DURATION {
+        let _ = decoder.decode(img);
+    }
+}
+
+/// Time a decode call, then keep timing more until the 95% bootstrap CI
+/// for the median is within `ADAPTIVE_TARGET_RELATIVE_HALF_WIDTH` of the
+/// median itself, or the iteration/wall-clock budget runs out. Returns one
+/// `(duration_us, decode result)` pair per measured iteration, plus
+/// whether convergence was reached.
+fn sample_adaptively(
+    decoder: &dyn QrDecoder,
+    img: &image::DynamicImage,
+) -> (Vec<(u128, Result<crate::decoders::DecodeResult>)>, bool) {
+    let mut samples = Vec::new();
+    let mut durations_us = Vec::new();
+    let start = Instant::now();
+
+    loop {
+        let iter_start = Instant::now();
+        let result = decoder.decode(img);
+        let duration = iter_start.elapsed().as_micros();
+
+        durations_us.push(duration as u64);
+        samples.push((duration, result));
+
+        let iteration = samples.len() as u32;
+        if iteration >= ADAPTIVE_MAX_ITERATIONS || start.elapsed() >= ADAPTIVE_MAX_DURATION {
+            return (samples, false);
+        }
+
+        if iteration >= ADAPTIVE_MIN_ITERATIONS && iteration % ADAPTIVE_CHECK_INTERVAL == 0 {
+            let ci = crate::stats::median_ci_with_resamples(&durations_us, ADAPTIVE_CHECK_RESAMPLES);
+            let half_width = (ci.upper - ci.lower) / 2.0;
+            if ci.point_estimate > 0.0 && half_width / ci.point_estimate <= ADAPTIVE_TARGET_RELATIVE_HALF_WIDTH
+            {
+                return (samples, true);
+            }
+        }
+    }
+}
+
+/// Run the benchmark across a worker-pool + bounded work queue.
+///
+/// Each `(decoder, image)` pair is an independent work item: a worker warms
+/// the decoder up, then adaptively samples it until the measured durations
+/// converge to within a target relative precision or a budget runs out (see
+/// [`sample_adaptively`]). Every decode is a single isolated call against a
+/// decoder and an already-loaded image, so `decoders` must be `Sync` (see
+/// [`crate::decoders::QrDecoder`]) and must not rely on any shared mutable
+/// state between calls, since multiple worker threads can decode with the
+/// same decoder concurrently.
+pub fn run_benchmark<W: std::io::Write + Send + 'static>(
     decoders: &[Box<dyn QrDecoder>],
     pairs: &[TestPair],
-    iterations: u32,
-    writer: &mut csv::Writer<W>,
+    writer: csv::Writer<W>,
     progress: &ProgressBar,
+    threads: usize,
 ) -> Result<()> {
+    // Load every image once up front; the queue only ever hands out
+    // (decoder, image) indices, never re-opens a file.
+    let mut loaded_pairs = Vec::with_capacity(pairs.len());
     for pair in pairs {
-        // Load image once
-        let img = match image::open(&pair.image_path) {
-            Ok(i) => i,
-            Err(_) => {
-                progress.inc(decoders.len() as u64);
-                continue;
-            }
-        };
-
-        for decoder in decoders {
-            // Warmup
-            let _ = decoder.decode(&img);
-
-            // Measurements
-            for i in 1..=iterations {
-                let start = Instant::now();
-                let result = decoder.decode(&img);
-                let duration = start.elapsed().as_micros();
-
-                let (status, decoded_text) = match result {
-                    Ok(decode_result) => {
-                        let mut status = "Incorrect".to_string();
-                        let text = decode_result.text.clone();
-
-                        // 1. Text Comparison (if expected text is available)
-                        if let Some(expected_text) = &pair.expected_text {
-                            let normalized_expected = expected_text.replace("\r\n", "\n").trim().to_string();
-                            let normalized_decoded = text.replace("\r\n", "\n").trim().to_string();
-                            
-                            if normalized_decoded == normalized_expected {
-                                status = "Correct".to_string();
-                            }
-                        } 
-                        // 2. Point/Detection Comparison (if expected points are available)
-                        else if let Some(expected_points_sets) = &pair.expected_points {
-                            if let Some(actual_points) = &decode_result.points {
-                                // Use a tolerance of 50.0 pixels (generous but ensures general alignment)
-                                if is_detection_correct(expected_points_sets, actual_points, 50.0) {
-                                    status = "Correct".to_string();
-                                }
-                            } else {
-                                status = "NoPoints".to_string();
-                            }
+        if let Ok(img) = image::open(&pair.image_path) {
+            loaded_pairs.push((pair.clone(), img));
+        } else {
+            progress.inc(decoders.len() as u64);
+        }
+    }
+
+    let queue = Arc::new(ArrayQueue::new(4096));
+    let filling = Arc::new(AtomicBool::new(true));
+
+    let (tx, rx) = mpsc::channel::<Measurement>();
+    let mut writer = writer;
+    let writer_handle = std::thread::spawn(move || -> Result<()> {
+        for record in rx {
+            writer.serialize(record)?;
+        }
+        writer.flush()?;
+        Ok(())
+    });
+
+    let decoders = Arc::new(decoders.iter().map(|d| d.as_ref()).collect::<Vec<_>>());
+    let loaded_pairs = Arc::new(loaded_pairs);
+    // Start from the jobs already counted for images that failed to load,
+    // so `set_position` below doesn't wind the bar back past them.
+    let completed = Arc::new(AtomicUsize::new(
+        (pairs.len() - loaded_pairs.len()) * decoders.len(),
+    ));
+
+    std::thread::scope(|scope| {
+        // Fill the queue from its own thread, concurrently with the
+        // workers below draining it. Workers must already be running
+        // before the fill loop starts for this to avoid ever blocking:
+        // since `thread::scope` spawns run immediately, it's enough that
+        // every `scope.spawn` call happens before anything here blocks.
+        {
+            let queue = Arc::clone(&queue);
+            let filling = Arc::clone(&filling);
+            let num_pairs = loaded_pairs.len();
+            let num_decoders = decoders.len();
+            scope.spawn(move || {
+                for pair_idx in 0..num_pairs {
+                    for decoder_idx in 0..num_decoders {
+                        // The queue is bounded, so back off with a
+                        // spin-yield if it's momentarily full; workers
+                        // drain it concurrently, so this always unblocks.
+                        let mut item = WorkItem {
+                            decoder_idx,
+                            pair_idx,
+                        };
+                        while let Err(rejected) = queue.push(item) {
+                            item = rejected;
+                            std::thread::yield_now();
                         }
+                    }
+                }
+                filling.store(false, Ordering::Release);
+            });
+        }
+
+        for _ in 0..threads.max(1) {
+            let queue = Arc::clone(&queue);
+            let filling = Arc::clone(&filling);
+            let decoders = Arc::clone(&decoders);
+            let loaded_pairs = Arc::clone(&loaded_pairs);
+            let completed = Arc::clone(&completed);
+            let tx = tx.clone();
+            let progress = progress.clone();
+
+            scope.spawn(move || {
+                loop {
+                    let item = match queue.pop() {
+                        Some(item) => item,
+                        None if filling.load(Ordering::Acquire) => {
+                            // Fill loop is still pushing; more work may
+                            // still arrive, so spin rather than exit.
+                            std::thread::yield_now();
+                            continue;
+                        }
+                        // Fill loop reported done. Pop once more: it may
+                        // have pushed its last item between our pop above
+                        // and this `filling` load, and the happens-before
+                        // edge through `filling`'s release/acquire
+                        // guarantees that item is now visible to us.
+                        None => match queue.pop() {
+                            Some(item) => item,
+                            None => break,
+                        },
+                    };
+
+                    let (pair, img) = &loaded_pairs[item.pair_idx];
+                    let decoder = decoders[item.decoder_idx];
+
+                    warm_up(decoder, img);
+                    let (samples, converged) = sample_adaptively(decoder, img);
 
-                        (status, text)
+                    for (iteration, (duration, result)) in samples.into_iter().enumerate() {
+                        let (status, decoded_text, iou) = match result {
+                            Ok(decode_result) => score_result(pair, &decode_result),
+                            Err(_) => ("Failed".to_string(), "".to_string(), None),
+                        };
+
+                        let record = Measurement {
+                            library: decoder.name().to_string(),
+                            category: pair.category.clone(),
+                            file_path: pair.image_path.to_string_lossy().to_string(),
+                            iteration: iteration as u32 + 1,
+                            duration_us: duration,
+                            status,
+                            expected_text: pair
+                                .expected_text
+                                .clone()
+                                .unwrap_or_else(|| "POINTS".to_string()),
+                            decoded_text,
+                            iou,
+                            converged,
+                        };
+
+                        // A send error means the writer thread hung up,
+                        // which only happens if it panicked; nothing
+                        // useful to do but stop this worker too.
+                        if tx.send(record).is_err() {
+                            return;
+                        }
                     }
-                    Err(_) => ("Failed".to_string(), "".to_string()),
-                };
-
-                let record = Measurement {
-                    library: decoder.name().to_string(),
-                    category: pair.category.clone(),
-                    file_path: pair.image_path.to_string_lossy().to_string(),
-                    iteration: i,
-                    duration_us: duration,
-                    status,
-                    expected_text: pair.expected_text.clone().unwrap_or_else(|| "POINTS".to_string()),
-                    decoded_text,
-                };
-
-                writer.serialize(record)?;
-            }
-            progress.inc(1);
+
+                    completed.fetch_add(1, Ordering::Relaxed);
+                    progress.set_position(completed.load(Ordering::Relaxed) as u64);
+                }
+            });
         }
-    }
+    });
+
+    drop(tx);
+    writer_handle
+        .join()
+        .map_err(|_| anyhow::anyhow!("writer thread panicked"))??;
+
     Ok(())
 }
@@ -1,10 +1,181 @@
 use anyhow::Result;
-use qr_benchmark::viz;
+use qr_benchmark::{report, scoring, stats, viz};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::env;
+use std::fs::File;
+
+#[derive(serde::Deserialize)]
+struct IouRecord {
+    library: String,
+    iou: Option<f32>,
+}
+
+#[derive(serde::Deserialize)]
+struct DurationRecord {
+    library: String,
+    category: String,
+    status: String,
+    duration_us: u64,
+}
+
+#[derive(Serialize)]
+struct GroupSummaryRecord {
+    library: String,
+    category: String,
+    samples: usize,
+    median_us: f64,
+    median_ci_low_us: f64,
+    median_ci_high_us: f64,
+    mean_ci_low_us: f64,
+    mean_ci_high_us: f64,
+    mild_outliers: usize,
+    severe_outliers: usize,
+}
+
+/// Per (library, category) bootstrap CIs and Tukey outlier counts over
+/// correct-decode durations, written alongside the raw measurements.
+fn write_group_stats(csv_path: &str) -> Result<()> {
+    let file = File::open(csv_path)?;
+    let mut rdr = csv::Reader::from_reader(file);
+
+    let mut durations: HashMap<(String, String), Vec<u64>> = HashMap::new();
+    for result in rdr.deserialize() {
+        let record: DurationRecord = result?;
+        if record.status == "Correct" {
+            durations
+                .entry((record.library, record.category))
+                .or_default()
+                .push(record.duration_us);
+        }
+    }
+
+    if durations.is_empty() {
+        return Ok(());
+    }
+
+    let mut groups: Vec<_> = durations.into_iter().collect();
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let summary_path = "stats_summary.csv";
+    let mut writer = csv::Writer::from_path(summary_path)?;
+    for ((library, category), durs) in groups {
+        let group_stats = stats::analyze_group(&durs);
+        writer.serialize(GroupSummaryRecord {
+            library,
+            category,
+            samples: durs.len(),
+            median_us: group_stats.median,
+            median_ci_low_us: group_stats.median_ci.lower,
+            median_ci_high_us: group_stats.median_ci.upper,
+            mean_ci_low_us: group_stats.mean_ci.lower,
+            mean_ci_high_us: group_stats.mean_ci.upper,
+            mild_outliers: group_stats.outliers.mild,
+            severe_outliers: group_stats.outliers.severe,
+        })?;
+    }
+    writer.flush()?;
+    println!("Wrote per-group bootstrap CIs and outlier counts to {}.", summary_path);
+
+    Ok(())
+}
+
+// Detection rate at a fixed IoU threshold, matching the default used
+// across most polygon detection benchmarks.
+const DETECTION_IOU_THRESHOLD: f32 = 0.5;
+
+fn print_detection_rates(csv_path: &str) -> Result<()> {
+    let file = File::open(csv_path)?;
+    let mut rdr = csv::Reader::from_reader(file);
+
+    let mut ious: HashMap<String, Vec<scoring::DetectionScore>> = HashMap::new();
+    for result in rdr.deserialize() {
+        let record: IouRecord = result?;
+        if let Some(iou) = record.iou {
+            ious.entry(record.library).or_default().push(
+                scoring::DetectionScore {
+                    matches: vec![scoring::DetectionMatch {
+                        expected_index: 0,
+                        detected_index: 0,
+                        iou,
+                    }],
+                    unmatched_expected: Vec::new(),
+                },
+            );
+        }
+    }
+
+    if ious.is_empty() {
+        return Ok(());
+    }
+
+    println!(
+        "\nDetection rate at IoU >= {:.2}:",
+        DETECTION_IOU_THRESHOLD
+    );
+    let mut libraries: Vec<&String> = ious.keys().collect();
+    libraries.sort();
+    for library in libraries {
+        let scores = &ious[library];
+        let rate = scoring::detection_rate(scores, DETECTION_IOU_THRESHOLD);
+        println!("  {}: {:.1}% ({} measurements)", library, rate * 100.0, scores.len());
+    }
+
+    Ok(())
+}
+
+fn print_regression_report(results: &[viz::RegressionResult]) {
+    println!("\nBaseline comparison (comparison.png):");
+    for r in results {
+        let verdict = if !r.significant {
+            "no significant change"
+        } else if r.relative_change < 0.0 {
+            "faster (regression-free)"
+        } else {
+            "SLOWER (regression)"
+        };
+        println!(
+            "  {}/{}: {:.0}us -> {:.0}us ({:+.1}%, p={:.3}) {}",
+            r.library,
+            r.category,
+            r.baseline_median_us,
+            r.current_median_us,
+            r.relative_change * 100.0,
+            r.p_value,
+            verdict
+        );
+    }
+}
 
 fn main() -> Result<()> {
-    // Check if a CSV file was passed as argument, otherwise use default
     let args: Vec<String> = env::args().collect();
+
+    // `analyze compare <baseline.csv> <current.csv>` runs a regression
+    // report against a saved baseline instead of the usual plot generation.
+    if args.len() > 1 && args[1] == "compare" {
+        if args.len() < 4 {
+            eprintln!("Usage: analyze compare <baseline.csv> <current.csv>");
+            return Ok(());
+        }
+        let results = viz::compare_baseline(&args[2], &args[3])?;
+        print_regression_report(&results);
+        return Ok(());
+    }
+
+    // `analyze report [csv]` emits a single self-contained HTML dashboard
+    // instead of a folder of loose PNGs.
+    if args.len() > 1 && args[1] == "report" {
+        let output_csv = if args.len() > 2 {
+            &args[2]
+        } else {
+            "raw_measurements.csv"
+        };
+        report::generate_report(output_csv, "report.html")?;
+        println!("Wrote report.html");
+        return Ok(());
+    }
+
+    // Check if a CSV file was passed as argument, otherwise use default
     let output_csv = if args.len() > 1 {
         &args[1]
     } else {
@@ -15,6 +186,9 @@ fn main() -> Result<()> {
     viz::generate_plots(output_csv)?;
     println!("Done! Check generated PNGs.");
 
+    print_detection_rates(output_csv)?;
+    write_group_stats(output_csv)?;
+
     Ok(())
 }
 
@@ -1,5 +1,7 @@
 use anyhow::Result;
-use qr_benchmark::decoders::{BardecoderDecoder, QrDecoder, RqrrDecoder, RxingDecoder};
+use qr_benchmark::decoders::{
+    BardecoderDecoder, QrDecoder, QueensRockDecoder, RqrrDecoder, RxingDecoder,
+};
 use serde::Serialize;
 use std::fs::File;
 use std::path::PathBuf;
@@ -83,6 +85,7 @@ fn main() -> Result<()> {
         Box::new(RqrrDecoder),
         Box::new(RxingDecoder),
         Box::new(BardecoderDecoder),
+        Box::new(QueensRockDecoder),
     ];
 
     let mut detections = Vec::new();
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 #[derive(Debug, Clone)]
@@ -14,11 +14,67 @@ pub struct TestPair {
     pub expected_points: Option<Vec<Vec<(f32, f32)>>>,
 }
 
-pub fn discover_test_data(
-    root_dirs: &[&str],
-    limit_per_category: Option<usize>,
-) -> Result<Vec<TestPair>> {
-    let mut pairs = Vec::new();
+struct GroundTruth {
+    expected_text: Option<String>,
+    expected_points: Option<Vec<Vec<(f32, f32)>>>,
+    // Set when the file looked like a points file but none of its lines
+    // parsed into a well-formed quad.
+    malformed: bool,
+}
+
+fn parse_ground_truth(content: &str, category: &str) -> GroundTruth {
+    // Check if it's a points file (starts with # or contains coordinates)
+    // Example format:
+    // # list of hand selected 2D points
+    // SETS
+    // x1 y1 x2 y2 x3 y3 x4 y4
+    // ...
+    if content.trim().starts_with('#') || category != "decoding" {
+        let mut sets = Vec::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.starts_with('#') || line == "SETS" || line.is_empty() {
+                continue;
+            }
+
+            // Each line is a set of points for one QR code
+            // "x1 y1 x2 y2 x3 y3 x4 y4"
+            let parts: Vec<&str> = line.split_whitespace().collect();
+
+            // We expect at least 4 pairs (8 numbers) for a quad
+            if parts.len() >= 8 {
+                let mut points = Vec::new();
+                for i in 0..4 {
+                    if let (Ok(x), Ok(y)) = (
+                        parts[i * 2].parse::<f32>(),
+                        parts[i * 2 + 1].parse::<f32>(),
+                    ) {
+                        points.push((x, y));
+                    }
+                }
+                if points.len() == 4 {
+                    sets.push(points);
+                }
+            }
+        }
+
+        GroundTruth {
+            expected_text: None,
+            malformed: sets.is_empty(),
+            expected_points: if sets.is_empty() { None } else { Some(sets) },
+        }
+    } else {
+        GroundTruth {
+            expected_text: Some(content.trim().replace("\r\n", "\n")),
+            expected_points: None,
+            malformed: false,
+        }
+    }
+}
+
+fn discover_images(root_dirs: &[&str], limit_per_category: Option<usize>) -> Result<Vec<PathBuf>> {
+    let mut images = Vec::new();
     let mut category_counts: HashMap<String, usize> = HashMap::new();
 
     for root in root_dirs {
@@ -26,92 +82,252 @@ pub fn discover_test_data(
             let entry = entry?;
             let path = entry.path();
 
-            if path.is_file() {
-                if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                    let ext = ext.to_lowercase();
-                    if ext == "png" || ext == "jpg" || ext == "jpeg" {
-                        let category = path
-                            .parent()
-                            .and_then(|p| p.file_name())
-                            .and_then(|n| n.to_str())
-                            .unwrap_or("unknown")
-                            .to_string();
-
-                        if let Some(limit) = limit_per_category {
-                            let count = category_counts.entry(category.clone()).or_insert(0);
-                            if *count >= limit {
-                                continue;
-                            }
-                            *count += 1;
-                        }
-
-                        let text_path = path.with_extension("txt");
-                        if text_path.exists() {
-                            let content = std::fs::read_to_string(&text_path)?;
-
-                            let mut expected_text = None;
-                            let mut expected_points = None;
-
-                            // Check if it's a points file (starts with # or contains coordinates)
-                            // Example format:
-                            // # list of hand selected 2D points
-                            // SETS
-                            // x1 y1 x2 y2 x3 y3 x4 y4
-                            // ...
-
-                            if content.trim().starts_with("#") || category != "decoding" {
-                                // Parse points
-                                let mut sets = Vec::new();
-
-                                for line in content.lines() {
-                                    let line = line.trim();
-                                    if line.starts_with("#") || line == "SETS" || line.is_empty() {
-                                        continue;
-                                    }
-
-                                    // Each line is a set of points for one QR code
-                                    // "x1 y1 x2 y2 x3 y3 x4 y4"
-                                    let parts: Vec<&str> = line.split_whitespace().collect();
-
-                                    // We expect at least 4 pairs (8 numbers) for a quad
-                                    if parts.len() >= 8 {
-                                        let mut points = Vec::new();
-                                        for i in 0..4 {
-                                            if let (Ok(x), Ok(y)) = (
-                                                parts[i * 2].parse::<f32>(),
-                                                parts[i * 2 + 1].parse::<f32>(),
-                                            ) {
-                                                points.push((x, y));
-                                            }
-                                        }
-                                        if points.len() == 4 {
-                                            sets.push(points);
-                                        }
-                                    }
-                                }
-
-                                if !sets.is_empty() {
-                                    expected_points = Some(sets);
-                                }
-                            } else {
-                                // It's text content
-                                expected_text = Some(content.trim().replace("\r\n", "\n"));
-                            }
-
-                            if expected_text.is_some() || expected_points.is_some() {
-                                pairs.push(TestPair {
-                                    image_path: path.to_path_buf(),
-                                    category,
-                                    expected_text,
-                                    expected_points,
-                                });
-                            }
-                        }
-                    }
+            if !path.is_file() {
+                continue;
+            }
+            let Some(ext) = path.extension().and_then(|e| e.to_str()) else {
+                continue;
+            };
+            let ext = ext.to_lowercase();
+            if ext != "png" && ext != "jpg" && ext != "jpeg" {
+                continue;
+            }
+
+            if let Some(limit) = limit_per_category {
+                let category = category_name(path);
+                let count = category_counts.entry(category).or_insert(0);
+                if *count >= limit {
+                    continue;
                 }
+                *count += 1;
             }
+
+            images.push(path.to_path_buf());
+        }
+    }
+
+    Ok(images)
+}
+
+fn category_name(path: &Path) -> String {
+    path.parent()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+pub fn discover_test_data(
+    root_dirs: &[&str],
+    limit_per_category: Option<usize>,
+) -> Result<Vec<TestPair>> {
+    let mut pairs = Vec::new();
+
+    for image_path in discover_images(root_dirs, limit_per_category)? {
+        let category = category_name(&image_path);
+
+        let text_path = image_path.with_extension("txt");
+        if !text_path.exists() {
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&text_path)?;
+        let ground_truth = parse_ground_truth(&content, &category);
+
+        if ground_truth.expected_text.is_some() || ground_truth.expected_points.is_some() {
+            pairs.push(TestPair {
+                image_path,
+                category,
+                expected_text: ground_truth.expected_text,
+                expected_points: ground_truth.expected_points,
+            });
         }
     }
 
     Ok(pairs)
 }
+
+/// Result of a pre-flight pass over the dataset: every pair that looks
+/// usable, plus every image or ground-truth file that didn't, so a single
+/// truncated JPEG or malformed points file doesn't abort a whole run.
+#[derive(Debug, Default)]
+pub struct ScanReport {
+    pub ok: Vec<TestPair>,
+    pub corrupt_images: Vec<(PathBuf, String)>,
+    pub malformed_ground_truth: Vec<PathBuf>,
+}
+
+impl ScanReport {
+    pub fn print_summary(&self) {
+        println!(
+            "Scanned dataset: {} ok, {} corrupt image(s), {} malformed ground-truth file(s)",
+            self.ok.len(),
+            self.corrupt_images.len(),
+            self.malformed_ground_truth.len()
+        );
+        for (path, reason) in &self.corrupt_images {
+            println!("  corrupt: {} ({})", path.display(), reason);
+        }
+        for path in &self.malformed_ground_truth {
+            println!("  malformed ground truth: {}", path.display());
+        }
+    }
+}
+
+/// Like [`discover_test_data`], but attempts to actually open every image
+/// (catching decode failures and zero-dimension images) and validates that
+/// ground-truth `.txt` files parse into well-formed quads, instead of
+/// panicking or silently skipping on the first bad file.
+pub fn scan_test_data(root_dirs: &[&str], limit_per_category: Option<usize>) -> Result<ScanReport> {
+    let mut report = ScanReport::default();
+
+    for image_path in discover_images(root_dirs, limit_per_category)? {
+        let category = category_name(&image_path);
+
+        match image::open(&image_path) {
+            Ok(img) if img.width() == 0 || img.height() == 0 => {
+                report
+                    .corrupt_images
+                    .push((image_path, "zero-dimension image".to_string()));
+                continue;
+            }
+            Err(e) => {
+                report.corrupt_images.push((image_path, e.to_string()));
+                continue;
+            }
+            Ok(_) => {}
+        }
+
+        let text_path = image_path.with_extension("txt");
+        if !text_path.exists() {
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&text_path)?;
+        let ground_truth = parse_ground_truth(&content, &category);
+
+        if ground_truth.malformed {
+            report.malformed_ground_truth.push(text_path);
+            continue;
+        }
+
+        if ground_truth.expected_text.is_some() || ground_truth.expected_points.is_some() {
+            report.ok.push(TestPair {
+                image_path,
+                category,
+                expected_text: ground_truth.expected_text,
+                expected_points: ground_truth.expected_points,
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+/// Quarantine every corrupt image found by [`scan_test_data`]: either move
+/// it (and its ground-truth file, if any) into a sibling `corrupt/`
+/// directory, or delete both outright.
+pub fn quarantine_corrupt(report: &ScanReport, delete: bool) -> Result<()> {
+    for (image_path, _reason) in &report.corrupt_images {
+        let text_path = image_path.with_extension("txt");
+
+        if delete {
+            std::fs::remove_file(image_path)?;
+            if text_path.exists() {
+                std::fs::remove_file(&text_path)?;
+            }
+            continue;
+        }
+
+        let parent = image_path.parent().unwrap_or_else(|| Path::new("."));
+        let quarantine_dir = parent.join("corrupt");
+        std::fs::create_dir_all(&quarantine_dir)?;
+
+        if let Some(file_name) = image_path.file_name() {
+            std::fs::rename(image_path, quarantine_dir.join(file_name))?;
+        }
+        if text_path.exists() {
+            if let Some(file_name) = text_path.file_name() {
+                std::fs::rename(&text_path, quarantine_dir.join(file_name))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn malformed_points_file_is_flagged() {
+        let content = "# hand selected points\nnot a valid line\n1 2 3\n";
+        let ground_truth = parse_ground_truth(content, "detection");
+        assert!(ground_truth.malformed);
+        assert!(ground_truth.expected_points.is_none());
+    }
+
+    #[test]
+    fn well_formed_points_file_is_not_malformed() {
+        let content = "# hand selected points\nSETS\n0 0 10 0 10 10 0 10\n";
+        let ground_truth = parse_ground_truth(content, "detection");
+        assert!(!ground_truth.malformed);
+        assert_eq!(
+            ground_truth.expected_points,
+            Some(vec![vec![
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 10.0),
+                (0.0, 10.0)
+            ]])
+        );
+    }
+
+    // Each test gets its own directory under the system temp dir so
+    // concurrently-run tests don't clobber each other's fixture files.
+    fn temp_dir(name: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!(
+            "qr_benchmark_data_test_{}_{}_{}",
+            std::process::id(),
+            name,
+            n
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn scan_test_data_flags_corrupt_image() {
+        let dir = temp_dir("corrupt_image");
+        std::fs::write(dir.join("bad.png"), b"not a real png").unwrap();
+
+        let root = dir.to_str().unwrap();
+        let report = scan_test_data(&[root], None).unwrap();
+
+        assert!(report.ok.is_empty());
+        assert_eq!(report.corrupt_images.len(), 1);
+        assert_eq!(report.corrupt_images[0].0, dir.join("bad.png"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn scan_test_data_flags_malformed_ground_truth() {
+        let dir = temp_dir("malformed_gt");
+        let img = image::RgbImage::new(4, 4);
+        img.save(dir.join("ok.png")).unwrap();
+        std::fs::write(dir.join("ok.txt"), "# points\nnot points\n").unwrap();
+
+        let root = dir.to_str().unwrap();
+        let report = scan_test_data(&[root], None).unwrap();
+
+        assert!(report.ok.is_empty());
+        assert_eq!(report.malformed_ground_truth, vec![dir.join("ok.txt")]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
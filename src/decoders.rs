@@ -15,7 +15,10 @@ pub struct DecodeResult {
     pub points: Option<Vec<(f32, f32)>>,
 }
 
-pub trait QrDecoder {
+// `Sync` is required because `benchmark::run_benchmark` shares decoders
+// across a worker-thread pool: every decode call must be independent, with
+// no interior mutable state shared between concurrent calls.
+pub trait QrDecoder: Sync {
     fn name(&self) -> &'static str;
     fn decode(&self, image: &DynamicImage) -> Result<DecodeResult>;
 }
@@ -219,6 +222,40 @@ impl QrDecoder for BardecoderDecoder {
     }
 }
 
+pub struct QueensRockDecoder;
+impl QrDecoder for QueensRockDecoder {
+    fn name(&self) -> &'static str {
+        "queens-rock"
+    }
+
+    fn decode(&self, image: &DynamicImage) -> Result<DecodeResult> {
+        let gray_image = image.to_luma8();
+        let scanner = queens_rock::Scanner::new(gray_image.width() as usize, gray_image.height() as usize);
+
+        let results = scanner.scan(gray_image.as_raw());
+        for result in results {
+            if let Ok(content) = result.decode(queens_rock::EccLevel::Any) {
+                let points = [
+                    result.top_left,
+                    result.top_right,
+                    result.bottom_right,
+                    result.bottom_left,
+                ]
+                .iter()
+                .map(|p: &queens_rock::Point| (p.x as f32, p.y as f32))
+                .collect();
+
+                return Ok(DecodeResult {
+                    text: content,
+                    points: Some(points),
+                });
+            }
+        }
+
+        Err(anyhow!("No QR code detected"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,8 @@
+pub mod benchmark;
+pub mod data;
+pub mod decoders;
+pub mod report;
+pub mod scoring;
+pub mod stats;
+pub mod structured_append;
+pub mod viz;
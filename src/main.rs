@@ -1,7 +1,7 @@
 use anyhow::Result;
 use clap::Parser;
 use indicatif::ProgressBar;
-use qr_benchmark::{benchmark, data, decoders};
+use qr_benchmark::{benchmark, data, decoders, structured_append};
 use std::fs::OpenOptions;
 use std::io::BufWriter;
 use std::path::Path;
@@ -15,10 +15,6 @@ struct Args {
     #[arg(short, long)]
     libs: Vec<String>,
 
-    /// Number of iterations to run per image.
-    #[arg(short = 'n', long, default_value_t = 5)]
-    iterations: u32,
-
     /// List of categories to benchmark (e.g., 'blurred', 'glare'). If empty, all categories are run.
     #[arg(short, long)]
     categories: Vec<String>,
@@ -26,11 +22,49 @@ struct Args {
     /// Output CSV file path.
     #[arg(short, long, default_value = "raw_measurements.csv")]
     output: String,
+
+    /// Number of worker threads to decode with. Defaults to the available parallelism.
+    #[arg(short = 'j', long)]
+    threads: Option<usize>,
+
+    /// Scan the dataset for corrupt images and malformed ground truth, print a
+    /// report, and exit without running the benchmark.
+    #[arg(long)]
+    scan: bool,
+
+    /// When scanning, move corrupt images (and their ground truth) into a
+    /// sibling `corrupt/` directory.
+    #[arg(long)]
+    quarantine: bool,
+
+    /// When scanning, delete corrupt images (and their ground truth) instead
+    /// of moving them. Takes precedence over `--quarantine`.
+    #[arg(long)]
+    delete_corrupt: bool,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
+    if args.scan {
+        println!("Scanning test data from {:?}...", DATA_DIRS);
+        let report = data::scan_test_data(DATA_DIRS, None)?;
+        report.print_summary();
+
+        if args.delete_corrupt {
+            println!("Deleting {} corrupt file(s)...", report.corrupt_images.len());
+            data::quarantine_corrupt(&report, true)?;
+        } else if args.quarantine {
+            println!(
+                "Moving {} corrupt file(s) into corrupt/ ...",
+                report.corrupt_images.len()
+            );
+            data::quarantine_corrupt(&report, false)?;
+        }
+
+        return Ok(());
+    }
+
     // 1. Data Discovery
     println!("Discovering test data from {:?}...", DATA_DIRS);
     let all_pairs = data::discover_test_data(DATA_DIRS, None)?;
@@ -74,6 +108,12 @@ fn main() -> Result<()> {
     #[cfg(feature = "zbar")]
     all_decoders.push(Box::new(decoders::ZBarDecoder));
 
+    #[cfg(feature = "queens-rock")]
+    all_decoders.push(Box::new(decoders::QueensRockDecoder));
+
+    #[cfg(feature = "rqrr")]
+    all_decoders.push(Box::new(structured_append::MultiSymbolDecoder));
+
     let decoders: Vec<Box<dyn decoders::QrDecoder>> = if args.libs.is_empty() {
         all_decoders
     } else {
@@ -114,17 +154,27 @@ fn main() -> Result<()> {
         .append(true)
         .open(output_csv)?;
 
-    let mut writer = csv::WriterBuilder::new()
+    let writer = csv::WriterBuilder::new()
         .has_headers(!should_append)
         .from_writer(BufWriter::new(file));
 
     // 4. Run Benchmark
-    println!("Running benchmark with {} iterations...", args.iterations);
+    let threads = args.threads.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+    println!(
+        "Running benchmark with adaptive sampling across {} threads...",
+        threads
+    );
+    // One unit per (image, decoder) job; each job adaptively samples until
+    // convergence rather than running a fixed iteration count, so the bar
+    // tracks jobs completed rather than individual decodes.
     let pb = ProgressBar::new((pairs.len() * decoders.len()) as u64);
 
-    benchmark::run_benchmark(&decoders, &pairs, args.iterations, &mut writer, &pb)?;
+    benchmark::run_benchmark(&decoders, &pairs, writer, &pb, threads)?;
     pb.finish_with_message("Benchmark complete");
-    writer.flush()?;
 
     println!("Benchmark finished. Data saved to {}.", output_csv);
     println!("To generate visualizations, run: cargo run --bin analyze");
@@ -0,0 +1,163 @@
+//! Self-contained HTML dashboard: embeds the success-rate, performance, and
+//! distribution charts plus summary tables from a measurement CSV into a
+//! single shareable file, rather than a folder of loose PNGs the user has
+//! to assemble by hand.
+
+use crate::{scoring, stats, viz};
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs::File;
+
+#[derive(serde::Deserialize)]
+struct Record {
+    library: String,
+    category: String,
+    status: String,
+    duration_us: u64,
+    iou: Option<f32>,
+}
+
+fn embed_png(path: &str) -> Option<String> {
+    let bytes = std::fs::read(path).ok()?;
+    Some(format!(
+        "data:image/png;base64,{}",
+        base64::encode(bytes)
+    ))
+}
+
+fn image_section(title: &str, path: &str) -> String {
+    match embed_png(path) {
+        Some(data_uri) => format!(
+            "<section><h2>{title}</h2><img alt=\"{title}\" src=\"{data_uri}\"></section>\n"
+        ),
+        None => format!("<section><h2>{title}</h2><p>(no data)</p></section>\n"),
+    }
+}
+
+/// Generate `output_path`, a single self-contained HTML file aggregating
+/// every chart and summary table derived from `csv_path`.
+pub fn generate_report(csv_path: &str, output_path: &str) -> Result<()> {
+    // Charts are rendered to the usual fixed PNG paths, then inlined as
+    // base64 data URIs so the report has no external file dependencies.
+    viz::generate_plots(csv_path)?;
+
+    let file = File::open(csv_path)?;
+    let mut rdr = csv::Reader::from_reader(file);
+
+    let mut success: HashMap<(String, String), (u32, u32)> = HashMap::new();
+    let mut durations: HashMap<(String, String), Vec<u64>> = HashMap::new();
+    let mut iou_scores: HashMap<String, Vec<scoring::DetectionScore>> = HashMap::new();
+    let mut libraries = std::collections::BTreeSet::new();
+    let mut categories = std::collections::BTreeSet::new();
+
+    for result in rdr.deserialize() {
+        let record: Record = result?;
+        libraries.insert(record.library.clone());
+        categories.insert(record.category.clone());
+
+        let key = (record.library.clone(), record.category.clone());
+        let entry = success.entry(key.clone()).or_insert((0, 0));
+        entry.1 += 1;
+
+        if record.status == "Correct" {
+            entry.0 += 1;
+            durations.entry(key).or_default().push(record.duration_us);
+        }
+
+        if let Some(iou) = record.iou {
+            iou_scores.entry(record.library).or_default().push(scoring::DetectionScore {
+                matches: vec![scoring::DetectionMatch {
+                    expected_index: 0,
+                    detected_index: 0,
+                    iou,
+                }],
+                unmatched_expected: Vec::new(),
+            });
+        }
+    }
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\">");
+    html.push_str("<title>QR Benchmark Report</title><style>");
+    html.push_str(
+        "body{font-family:sans-serif;margin:2rem;} \
+         table{border-collapse:collapse;margin-bottom:2rem;} \
+         th,td{border:1px solid #ccc;padding:4px 10px;text-align:right;} \
+         th:first-child,td:first-child{text-align:left;} \
+         img{max-width:100%;} \
+         nav ul{display:flex;gap:1rem;list-style:none;padding:0;}",
+    );
+    html.push_str("</style></head><body>\n");
+    html.push_str("<h1>QR Benchmark Report</h1>\n");
+
+    html.push_str("<nav><ul>");
+    for library in &libraries {
+        let _ = write!(html, "<li><a href=\"#lib-{library}\">{library}</a></li>");
+    }
+    for category in &categories {
+        let _ = write!(html, "<li><a href=\"#cat-{category}\">{category}</a></li>");
+    }
+    html.push_str("</ul></nav>\n");
+
+    html.push_str(&image_section("Success Rate by Category", "success_rates.png"));
+    html.push_str(&image_section("Median Duration (Correct Decodes)", "performance.png"));
+    html.push_str(&image_section("Performance Distribution (KDE)", "performance_dist.png"));
+    html.push_str(&image_section("Performance Distribution (Box + Violin)", "performance_box.png"));
+
+    html.push_str("<h2>Per-library / per-category summary</h2>\n");
+    html.push_str("<table><tr><th>Library</th><th>Category</th><th>Success rate</th><th>Median (us)</th><th>95% CI</th><th>Mild outliers</th><th>Severe outliers</th></tr>\n");
+
+    let mut groups: Vec<&(String, String)> = success.keys().collect();
+    groups.sort();
+    for group in groups {
+        let (correct, total) = success[group];
+        let rate = correct as f64 / total.max(1) as f64 * 100.0;
+
+        let (median, ci, mild, severe) = match durations.get(group) {
+            Some(durs) if !durs.is_empty() => {
+                let group_stats = stats::analyze_group(durs);
+                (
+                    format!("{:.0}", group_stats.median),
+                    format!(
+                        "[{:.0}, {:.0}]",
+                        group_stats.median_ci.lower, group_stats.median_ci.upper
+                    ),
+                    group_stats.outliers.mild.to_string(),
+                    group_stats.outliers.severe.to_string(),
+                )
+            }
+            _ => ("-".to_string(), "-".to_string(), "-".to_string(), "-".to_string()),
+        };
+
+        let _ = write!(
+            html,
+            "<tr id=\"lib-{}\" class=\"cat-{}\"><td>{}</td><td id=\"cat-{}\">{}</td><td>{:.1}%</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            group.0, group.1, group.0, group.1, group.1, rate, median, ci, mild, severe
+        );
+    }
+    html.push_str("</table>\n");
+
+    if !iou_scores.is_empty() {
+        html.push_str("<h2>Detection rate (IoU &ge; 0.5)</h2>\n");
+        html.push_str("<table><tr><th>Library</th><th>Detection rate</th><th>Measurements scored</th></tr>\n");
+        for library in &libraries {
+            if let Some(scores) = iou_scores.get(library) {
+                let rate = scoring::detection_rate(scores, 0.5);
+                let _ = write!(
+                    html,
+                    "<tr><td>{}</td><td>{:.1}%</td><td>{}</td></tr>\n",
+                    library,
+                    rate * 100.0,
+                    scores.len()
+                );
+            }
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("</body></html>\n");
+
+    std::fs::write(output_path, html)?;
+    Ok(())
+}
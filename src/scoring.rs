@@ -0,0 +1,293 @@
+//! Detection-quality scoring via polygon Intersection-over-Union (IoU).
+//!
+//! `decoders::DecodeResult::points` and `data::TestPair::expected_points`
+//! both carry four-corner quads. This module scores how well a detected
+//! quad lines up with a ground-truth quad geometrically, rather than only
+//! whether the decoded text matched.
+
+/// Shoelace formula: signed area of a simple polygon, positive for
+/// counter-clockwise winding.
+fn signed_area(points: &[(f32, f32)]) -> f32 {
+    let n = points.len();
+    let mut sum = 0.0;
+    for i in 0..n {
+        let (x1, y1) = points[i];
+        let (x2, y2) = points[(i + 1) % n];
+        sum += x1 * y2 - x2 * y1;
+    }
+    sum / 2.0
+}
+
+fn area(points: &[(f32, f32)]) -> f32 {
+    signed_area(points).abs()
+}
+
+/// Whether every turn between consecutive edges bends the same way.
+/// Sutherland-Hodgman clipping assumes a convex clip polygon, so a
+/// concave or self-intersecting ("bowtie") quad -- which can still have
+/// nonzero shoelace area -- would otherwise get clipped as if it were
+/// convex and produce a meaningless IoU.
+fn is_convex(points: &[(f32, f32)]) -> bool {
+    let n = points.len();
+    let mut winding = 0.0f32;
+    for i in 0..n {
+        let (x1, y1) = points[i];
+        let (x2, y2) = points[(i + 1) % n];
+        let (x3, y3) = points[(i + 2) % n];
+        let cross = (x2 - x1) * (y3 - y2) - (y2 - y1) * (x3 - x2);
+        if cross.abs() < 1e-6 {
+            continue;
+        }
+        if winding == 0.0 {
+            winding = cross.signum();
+        } else if cross.signum() != winding {
+            return false;
+        }
+    }
+    true
+}
+
+/// Sutherland-Hodgman requires both polygons to wind the same way for the
+/// inside/outside half-plane test to be consistent.
+fn counter_clockwise(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    if signed_area(points) < 0.0 {
+        let mut reversed = points.to_vec();
+        reversed.reverse();
+        reversed
+    } else {
+        points.to_vec()
+    }
+}
+
+fn is_inside(edge_start: (f32, f32), edge_end: (f32, f32), point: (f32, f32)) -> bool {
+    let (ax, ay) = edge_start;
+    let (bx, by) = edge_end;
+    let (px, py) = point;
+    (bx - ax) * (py - ay) - (by - ay) * (px - ax) >= 0.0
+}
+
+fn line_intersection(
+    p1: (f32, f32),
+    p2: (f32, f32),
+    p3: (f32, f32),
+    p4: (f32, f32),
+) -> (f32, f32) {
+    let (x1, y1) = p1;
+    let (x2, y2) = p2;
+    let (x3, y3) = p3;
+    let (x4, y4) = p4;
+
+    let denom = (x1 - x2) * (y3 - y4) - (y1 - y2) * (x3 - x4);
+    if denom.abs() < 1e-6 {
+        return p2;
+    }
+    let t = ((x1 - x3) * (y3 - y4) - (y1 - y3) * (x3 - x4)) / denom;
+    (x1 + t * (x2 - x1), y1 + t * (y2 - y1))
+}
+
+/// Clip `subject` against the convex polygon `clip`, both wound
+/// counter-clockwise, keeping the portion of `subject` inside `clip`.
+fn clip_polygon(subject: &[(f32, f32)], clip: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut output = subject.to_vec();
+
+    for i in 0..clip.len() {
+        if output.is_empty() {
+            break;
+        }
+        let edge_start = clip[i];
+        let edge_end = clip[(i + 1) % clip.len()];
+
+        let input = output;
+        output = Vec::with_capacity(input.len());
+
+        for j in 0..input.len() {
+            let current = input[j];
+            let prev = input[(j + input.len() - 1) % input.len()];
+
+            let current_inside = is_inside(edge_start, edge_end, current);
+            let prev_inside = is_inside(edge_start, edge_end, prev);
+
+            if current_inside {
+                if !prev_inside {
+                    output.push(line_intersection(prev, current, edge_start, edge_end));
+                }
+                output.push(current);
+            } else if prev_inside {
+                output.push(line_intersection(prev, current, edge_start, edge_end));
+            }
+        }
+    }
+
+    output
+}
+
+/// Intersection-over-Union of two quads, via Sutherland-Hodgman clipping
+/// and the shoelace formula. `None` means the input itself is unusable --
+/// wrong point count, zero area, or a non-convex/self-intersecting quad
+/// (Sutherland-Hodgman requires a convex clip polygon) -- as distinct from
+/// `Some(0.0)`, a well-formed quad with genuinely no overlap. Callers
+/// should only fall back to a different check (e.g. corner distance) on
+/// `None`, not on a real zero IoU.
+pub fn quad_iou(a: &[(f32, f32)], b: &[(f32, f32)]) -> Option<f32> {
+    if a.len() != 4 || b.len() != 4 {
+        return None;
+    }
+
+    let area_a = area(a);
+    let area_b = area(b);
+    if area_a <= 0.0 || area_b <= 0.0 {
+        return None;
+    }
+
+    if !is_convex(a) || !is_convex(b) {
+        return None;
+    }
+
+    let a = counter_clockwise(a);
+    let b = counter_clockwise(b);
+
+    let intersection = clip_polygon(&a, &b);
+    let inter_area = area(&intersection);
+
+    Some(inter_area / (area_a + area_b - inter_area))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DetectionMatch {
+    pub expected_index: usize,
+    pub detected_index: usize,
+    pub iou: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DetectionScore {
+    pub matches: Vec<DetectionMatch>,
+    pub unmatched_expected: Vec<usize>,
+}
+
+impl DetectionScore {
+    /// IoU of the best detection-to-ground-truth match in this image, or
+    /// 0.0 if nothing matched at all.
+    pub fn best_iou(&self) -> f32 {
+        self.matches
+            .iter()
+            .map(|m| m.iou)
+            .fold(0.0, |a, b| a.max(b))
+    }
+}
+
+/// Greedily assign each detected quad to the best-matching (highest-IoU)
+/// ground-truth quad, descending by IoU, for the multi-QR case. Ground
+/// truth quads left without a detection above 0 IoU count as misses.
+pub fn match_detections(
+    expected: &[Vec<(f32, f32)>],
+    detected: &[Vec<(f32, f32)>],
+) -> DetectionScore {
+    let mut candidates = Vec::new();
+    for (expected_index, e) in expected.iter().enumerate() {
+        for (detected_index, d) in detected.iter().enumerate() {
+            if let Some(iou) = quad_iou(e, d) {
+                if iou > 0.0 {
+                    candidates.push(DetectionMatch {
+                        expected_index,
+                        detected_index,
+                        iou,
+                    });
+                }
+            }
+        }
+    }
+    candidates.sort_by(|a, b| b.iou.partial_cmp(&a.iou).unwrap());
+
+    let mut used_expected = vec![false; expected.len()];
+    let mut used_detected = vec![false; detected.len()];
+    let mut matches = Vec::new();
+
+    for candidate in candidates {
+        if used_expected[candidate.expected_index] || used_detected[candidate.detected_index] {
+            continue;
+        }
+        used_expected[candidate.expected_index] = true;
+        used_detected[candidate.detected_index] = true;
+        matches.push(candidate);
+    }
+
+    let unmatched_expected = used_expected
+        .iter()
+        .enumerate()
+        .filter(|(_, &used)| !used)
+        .map(|(i, _)| i)
+        .collect();
+
+    DetectionScore {
+        matches,
+        unmatched_expected,
+    }
+}
+
+/// Dataset-level detection rate: fraction of ground-truth quads across
+/// every scored image that were matched at or above `threshold` IoU.
+pub fn detection_rate(scores: &[DetectionScore], threshold: f32) -> f64 {
+    let mut total = 0usize;
+    let mut hits = 0usize;
+    for score in scores {
+        total += score.matches.len() + score.unmatched_expected.len();
+        hits += score.matches.iter().filter(|m| m.iou >= threshold).count();
+    }
+    if total == 0 {
+        0.0
+    } else {
+        hits as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_quads_have_iou_one() {
+        let quad = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        assert!((quad_iou(&quad, &quad).unwrap() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn disjoint_quads_have_iou_zero() {
+        let a = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        let b = vec![(20.0, 20.0), (30.0, 20.0), (30.0, 30.0), (20.0, 30.0)];
+        assert_eq!(quad_iou(&a, &b), Some(0.0));
+    }
+
+    #[test]
+    fn half_overlap_gives_one_third_iou() {
+        let a = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        let b = vec![(5.0, 0.0), (15.0, 0.0), (15.0, 10.0), (5.0, 10.0)];
+        // intersection = 5x10 = 50, union = 100 + 100 - 50 = 150
+        assert!((quad_iou(&a, &b).unwrap() - 50.0 / 150.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn self_intersecting_quad_is_degenerate_not_zero_overlap() {
+        // Crossed/concave quad with nonzero shoelace area -- `area()`
+        // alone wouldn't catch this as degenerate. Must come back as
+        // `None`, not `Some(0.0)`, so callers don't mistake it for a
+        // well-formed quad with no overlap.
+        let bowtie = vec![(0.0, 0.0), (10.0, 0.0), (2.0, 10.0), (8.0, 10.0)];
+        let square = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        assert_eq!(quad_iou(&bowtie, &square), None);
+    }
+
+    #[test]
+    fn greedy_matching_prefers_best_iou() {
+        let expected = vec![
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![(20.0, 0.0), (30.0, 0.0), (30.0, 10.0), (20.0, 10.0)],
+        ];
+        let detected = vec![vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]];
+
+        let score = match_detections(&expected, &detected);
+        assert_eq!(score.matches.len(), 1);
+        assert_eq!(score.matches[0].expected_index, 0);
+        assert_eq!(score.unmatched_expected, vec![1]);
+    }
+}
@@ -0,0 +1,144 @@
+//! Bootstrap confidence intervals and Tukey-fence outlier classification
+//! for benchmark samples.
+
+use rand::Rng;
+
+const BOOTSTRAP_RESAMPLES: usize = 10_000;
+
+pub struct BootstrapCi {
+    pub point_estimate: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OutlierCounts {
+    pub mild: usize,
+    pub severe: usize,
+}
+
+pub struct GroupStats {
+    pub median: f64,
+    pub median_ci: BootstrapCi,
+    pub mean_ci: BootstrapCi,
+    pub outliers: OutlierCounts,
+}
+
+fn sorted(values: &[f64]) -> Vec<f64> {
+    let mut v = values.to_vec();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    v
+}
+
+fn median_of_sorted(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    if n % 2 == 0 {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    } else {
+        sorted[n / 2]
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn percentile_of_sorted(sorted: &[f64], p: f64) -> f64 {
+    let idx = ((sorted.len() - 1) as f64 * p).round() as usize;
+    sorted[idx]
+}
+
+/// Resample `samples` with replacement `resamples` times, compute
+/// `statistic` on each resample, and take the 2.5th/97.5th percentiles of
+/// the resulting estimates as a 95% confidence interval.
+fn bootstrap_ci_with_resamples(
+    samples: &[f64],
+    resamples: usize,
+    statistic: impl Fn(&[f64]) -> f64,
+) -> BootstrapCi {
+    let mut rng = rand::thread_rng();
+    let n = samples.len();
+
+    let mut estimates = Vec::with_capacity(resamples);
+    for _ in 0..resamples {
+        let resample: Vec<f64> = (0..n).map(|_| samples[rng.gen_range(0..n)]).collect();
+        estimates.push(statistic(&resample));
+    }
+    estimates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    BootstrapCi {
+        point_estimate: statistic(samples),
+        lower: percentile_of_sorted(&estimates, 0.025),
+        upper: percentile_of_sorted(&estimates, 0.975),
+    }
+}
+
+fn bootstrap_ci(samples: &[f64], statistic: impl Fn(&[f64]) -> f64) -> BootstrapCi {
+    bootstrap_ci_with_resamples(samples, BOOTSTRAP_RESAMPLES, statistic)
+}
+
+/// Bootstrap CI for the median with a caller-chosen resample count, for
+/// callers that need many CIs cheaply (e.g. an adaptive sampling loop
+/// checking for convergence every few iterations) and can't afford the
+/// full `BOOTSTRAP_RESAMPLES` on every check.
+pub fn median_ci_with_resamples(durations_us: &[u64], resamples: usize) -> BootstrapCi {
+    let values: Vec<f64> = durations_us.iter().map(|&d| d as f64).collect();
+    bootstrap_ci_with_resamples(&values, resamples, |s| median_of_sorted(&sorted(s)))
+}
+
+/// Classify samples using Tukey fences: beyond `Q1 - 1.5*IQR` /
+/// `Q3 + 1.5*IQR` is a mild outlier, beyond `3*IQR` is severe.
+pub fn tukey_outliers(samples: &[f64]) -> OutlierCounts {
+    let sorted = sorted(samples);
+    let q1 = percentile_of_sorted(&sorted, 0.25);
+    let q3 = percentile_of_sorted(&sorted, 0.75);
+    let iqr = q3 - q1;
+
+    let mild_bounds = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+    let severe_bounds = (q1 - 3.0 * iqr, q3 + 3.0 * iqr);
+
+    let mut counts = OutlierCounts::default();
+    for &x in samples {
+        if x < severe_bounds.0 || x > severe_bounds.1 {
+            counts.severe += 1;
+        } else if x < mild_bounds.0 || x > mild_bounds.1 {
+            counts.mild += 1;
+        }
+    }
+    counts
+}
+
+/// Median, 95% bootstrap CIs for the median and mean, and Tukey outlier
+/// counts for one (library, category) group of durations.
+pub fn analyze_group(durations_us: &[u64]) -> GroupStats {
+    let values: Vec<f64> = durations_us.iter().map(|&d| d as f64).collect();
+    let sorted_values = sorted(&values);
+
+    GroupStats {
+        median: median_of_sorted(&sorted_values),
+        median_ci: bootstrap_ci(&values, |s| median_of_sorted(&sorted(s))),
+        mean_ci: bootstrap_ci(&values, mean),
+        outliers: tukey_outliers(&values),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tukey_outliers_flags_extreme_values() {
+        let mut samples: Vec<f64> = (0..20).map(|x| x as f64).collect();
+        samples.push(1000.0); // way beyond 3*IQR
+        let counts = tukey_outliers(&samples);
+        assert_eq!(counts.severe, 1);
+    }
+
+    #[test]
+    fn bootstrap_ci_brackets_the_point_estimate() {
+        let samples: Vec<f64> = (1..=100).map(|x| x as f64).collect();
+        let ci = bootstrap_ci(&samples, mean);
+        assert!(ci.lower <= ci.point_estimate);
+        assert!(ci.point_estimate <= ci.upper);
+    }
+}
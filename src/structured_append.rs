@@ -0,0 +1,45 @@
+use crate::decoders::{DecodeResult, QrDecoder};
+use anyhow::{anyhow, Result};
+use image::DynamicImage;
+
+/// Tries each QR grid rqrr detects in an image in turn and returns the
+/// first one that decodes successfully, with its text and corner points.
+///
+/// Structured Append sequences (ISO/IEC 18004 section 8.1) are not
+/// reassembled: symbols are not grouped by sequence header or
+/// concatenated, and the other symbols in the image are not decoded once
+/// one succeeds. This uses only rqrr's `Grid::decode` (final decoded text)
+/// and `Grid::bounds`. Unlike `RqrrDecoder`, it does not retry on an
+/// inverted image when no grid decodes.
+pub struct MultiSymbolDecoder;
+
+impl QrDecoder for MultiSymbolDecoder {
+    fn name(&self) -> &'static str {
+        "multi-symbol"
+    }
+
+    fn decode(&self, image: &DynamicImage) -> Result<DecodeResult> {
+        let gray_image = image.to_luma8();
+        let mut img = rqrr::PreparedImage::prepare_from_greyscale(
+            gray_image.width() as usize,
+            gray_image.height() as usize,
+            |x, y| gray_image.get_pixel(x as u32, y as u32)[0],
+        );
+
+        for grid in img.detect_grids() {
+            if let Ok((_meta, content)) = grid.decode() {
+                let corners = grid
+                    .bounds
+                    .iter()
+                    .map(|p| (p.x as f32, p.y as f32))
+                    .collect();
+                return Ok(DecodeResult {
+                    text: content,
+                    points: Some(corners),
+                });
+            }
+        }
+
+        Err(anyhow!("No QR code detected"))
+    }
+}
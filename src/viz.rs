@@ -1,5 +1,6 @@
 use anyhow::Result;
 use plotters::prelude::*;
+use rand::seq::SliceRandom;
 use std::collections::HashMap;
 use std::fs::File;
 
@@ -55,12 +56,74 @@ pub fn generate_plots(csv_path: &str) -> Result<()> {
     // 2. Performance Plot (Summary)
     draw_performance(&sorted_libraries, &durations)?;
 
-    // 3. Performance Distribution (Histogram/PDF)
+    // 3. Performance Distribution (KDE)
     draw_performance_dist(&sorted_libraries, &durations)?;
 
+    // 4. Performance Box/Violin Plot
+    draw_performance_box(&sorted_libraries, &durations)?;
+
     Ok(())
 }
 
+const KDE_GRID_POINTS: usize = 200;
+
+fn standard_normal_kernel(u: f64) -> f64 {
+    (-u * u / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let idx = ((sorted.len() - 1) as f64 * p).round() as usize;
+    sorted[idx]
+}
+
+/// Bandwidth via Silverman's rule of thumb: `0.9 * min(stddev, IQR/1.34) * n^(-1/5)`.
+fn silverman_bandwidth(sorted: &[f64]) -> f64 {
+    let n = sorted.len() as f64;
+    let mean = sorted.iter().sum::<f64>() / n;
+    let variance = sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    let stddev = variance.sqrt();
+
+    let iqr = percentile(sorted, 0.75) - percentile(sorted, 0.25);
+    let spread = stddev.min(iqr / 1.34);
+
+    let h = 0.9 * spread * n.powf(-1.0 / 5.0);
+    if h <= 0.0 {
+        // Degenerate sample (e.g. every value identical): fall back to a
+        // bandwidth scaled to the data rather than a fixed epsilon.
+        // `durations_us` are in the hundreds/thousands, so an absolute
+        // 1e-6 is effectively zero at that scale and collapses the kernel
+        // to a near-invisible spike instead of avoiding one.
+        (mean.abs() * 0.01).max(1.0)
+    } else {
+        h
+    }
+}
+
+/// Evaluate a Gaussian KDE for `samples` on `KDE_GRID_POINTS + 1` evenly
+/// spaced points spanning `0..=max_dur`.
+fn kde(samples: &[u64], max_dur: f64) -> Vec<(f64, f64)> {
+    let sorted: Vec<f64> = {
+        let mut s: Vec<f64> = samples.iter().map(|&d| d as f64).collect();
+        s.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        s
+    };
+    let n = sorted.len() as f64;
+    let h = silverman_bandwidth(&sorted);
+
+    let step = max_dur / KDE_GRID_POINTS as f64;
+    (0..=KDE_GRID_POINTS)
+        .map(|i| {
+            let t = i as f64 * step;
+            let density = sorted
+                .iter()
+                .map(|&x| standard_normal_kernel((t - x) / h))
+                .sum::<f64>()
+                / (n * h);
+            (t, density)
+        })
+        .collect()
+}
+
 fn draw_performance_dist(libraries: &[String], durations: &HashMap<String, Vec<u64>>) -> Result<()> {
     let root = BitMapBackend::new("performance_dist.png", (1024, 768)).into_drawing_area();
     root.fill(&WHITE)?;
@@ -69,25 +132,39 @@ fn draw_performance_dist(libraries: &[String], durations: &HashMap<String, Vec<u
     for list in durations.values() {
         all_durations.extend(list.iter().cloned());
     }
-    
+
     if all_durations.is_empty() {
         return Ok(()); // Nothing to draw
     }
 
     all_durations.sort();
     // Clip outliers for better visualization (e.g., P98)
-    let max_dur = all_durations[(all_durations.len() as f64 * 0.98) as usize];
-    
-    let bucket_count = 50;
-    let bucket_size = (max_dur as f64 / bucket_count as f64).ceil() as u64;
-    let bucket_size = bucket_size.max(1); // avoid 0
+    let max_dur = all_durations[(all_durations.len() as f64 * 0.98) as usize] as f64;
+
+    let curves: Vec<(usize, Vec<(f64, f64)>)> = libraries
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, lib)| {
+            let durs = durations.get(lib)?;
+            if durs.is_empty() {
+                return None;
+            }
+            Some((idx, kde(durs, max_dur)))
+        })
+        .collect();
+
+    let max_density = curves
+        .iter()
+        .flat_map(|(_, points)| points.iter().map(|(_, d)| *d))
+        .fold(0.0_f64, f64::max)
+        .max(1e-9);
 
     let mut chart = ChartBuilder::on(&root)
-        .caption("Performance Distribution (PDF)", ("sans-serif", 40))
+        .caption("Performance Distribution (KDE)", ("sans-serif", 40))
         .margin(20)
         .x_label_area_size(50)
         .y_label_area_size(50)
-        .build_cartesian_2d(0u64..(max_dur + bucket_size), 0.0..1.0)?; // Normalized frequency
+        .build_cartesian_2d(0.0..max_dur, 0.0..(max_density * 1.1))?;
 
     chart
         .configure_mesh()
@@ -95,47 +172,14 @@ fn draw_performance_dist(libraries: &[String], durations: &HashMap<String, Vec<u
         .y_desc("Density")
         .draw()?;
 
-    for (idx, lib) in libraries.iter().enumerate() {
-        if let Some(durs) = durations.get(lib) {
-            let color = Palette99::pick(idx);
-            
-            // Build histogram
-            let mut buckets = HashMap::new();
-            for &d in durs {
-                if d <= max_dur {
-                    let b = d / bucket_size;
-                    *buckets.entry(b).or_insert(0) += 1;
-                }
-            }
-            
-            let total = durs.len() as f64;
-            let mut points = Vec::new();
-            
-            for b in 0..=bucket_count {
-                 let count = *buckets.get(&(b as u64)).unwrap_or(&0);
-                 let density = count as f64 / total;
-                 points.push((b as u64 * bucket_size, density));
-            }
-            // Add end point to drop line
-            points.push(((bucket_count as u64 + 1) * bucket_size, 0.0));
-
-            chart
-                .draw_series(LineSeries::new(
-                    points,
-                    &color,
-                ))?
-                .label(lib)
-                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
-                
-             // Optional: Fill area with low opacity
-             /*
-             chart.draw_series(AreaSeries::new(
-                points,
-                0.0,
-                &color.mix(0.2),
-             ))?;
-             */
-        }
+    for (idx, points) in curves {
+        let lib = &libraries[idx];
+        let color = Palette99::pick(idx);
+
+        chart
+            .draw_series(LineSeries::new(points, &color))?
+            .label(lib)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2)));
     }
 
     chart
@@ -147,6 +191,104 @@ fn draw_performance_dist(libraries: &[String], durations: &HashMap<String, Vec<u
     Ok(())
 }
 
+/// Box plot (Q1-Q3 box, median line, 1.5*IQR whiskers, outlier points
+/// beyond the whiskers) per library, with an optional mirrored KDE
+/// silhouette (violin) using the same kernel density as
+/// `draw_performance_dist`. Makes tail latency and spread visible in a way
+/// a single median bar can't.
+fn draw_performance_box(libraries: &[String], durations: &HashMap<String, Vec<u64>>) -> Result<()> {
+    let root = BitMapBackend::new("performance_box.png", (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut all_durations: Vec<u64> = durations.values().flatten().cloned().collect();
+    if all_durations.is_empty() {
+        return Ok(());
+    }
+    all_durations.sort();
+    // Clip outliers for better visualization (e.g., P98), same as the KDE plot.
+    let max_dur = all_durations[(all_durations.len() as f64 * 0.98) as usize] as f64;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Performance Distribution (Box + Violin)", ("sans-serif", 30))
+        .margin(20)
+        .x_label_area_size(60)
+        .y_label_area_size(60)
+        .build_cartesian_2d(0i32..libraries.len() as i32, 0.0..(max_dur * 1.1))?;
+
+    chart
+        .configure_mesh()
+        .x_labels(libraries.len())
+        .x_label_formatter(&|x| {
+            let idx = *x as usize;
+            libraries.get(idx).cloned().unwrap_or_default()
+        })
+        .y_desc("Duration (us)")
+        .draw()?;
+
+    for (idx, lib) in libraries.iter().enumerate() {
+        let Some(durs) = durations.get(lib) else {
+            continue;
+        };
+        if durs.is_empty() {
+            continue;
+        }
+
+        let color = Palette99::pick(idx);
+        let x = idx as i32;
+
+        // Violin silhouette: mirrored KDE, drawn first so the box sits on top.
+        let kde_points = kde(durs, max_dur);
+        let kde_max = kde_points
+            .iter()
+            .map(|(_, d)| *d)
+            .fold(0.0_f64, f64::max)
+            .max(1e-9);
+        let violin_half_width = 0.35;
+        let mut silhouette: Vec<(f64, f64)> = kde_points
+            .iter()
+            .map(|&(t, d)| (x as f64 - (d / kde_max) * violin_half_width, t))
+            .collect();
+        silhouette.extend(
+            kde_points
+                .iter()
+                .rev()
+                .map(|&(t, d)| (x as f64 + (d / kde_max) * violin_half_width, t)),
+        );
+        chart.draw_series(std::iter::once(Polygon::new(silhouette, color.mix(0.2))))?;
+
+        // Individual points beyond the 1.5*IQR whiskers.
+        let values: Vec<f64> = {
+            let mut v: Vec<f64> = durs.iter().map(|&d| d as f64).collect();
+            v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            v
+        };
+
+        let quartiles = Quartiles::new(&values);
+        chart.draw_series(std::iter::once(
+            Boxplot::new_vertical(x, &quartiles)
+                .width(30)
+                .whisker_width(0.5)
+                .style(color.stroke_width(2)),
+        ))?;
+
+        let q1 = percentile(&values, 0.25);
+        let q3 = percentile(&values, 0.75);
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        for &v in &values {
+            if v < lower_fence || v > upper_fence {
+                chart.draw_series(std::iter::once(Circle::new(
+                    (x, v),
+                    3,
+                    color.filled(),
+                )))?;
+            }
+        }
+    }
+
+    Ok(())
+}
 
 fn draw_success_rates(
     categories: &[String],
@@ -290,3 +432,172 @@ fn draw_performance(libraries: &[String], durations: &HashMap<String, Vec<u64>>)
 
     Ok(())
 }
+
+fn load_correct_durations_by_group(csv_path: &str) -> Result<HashMap<(String, String), Vec<u64>>> {
+    let file = File::open(csv_path)?;
+    let mut rdr = csv::Reader::from_reader(file);
+
+    let mut durations: HashMap<(String, String), Vec<u64>> = HashMap::new();
+    for result in rdr.deserialize() {
+        let record: Record = result?;
+        if record.status == "Correct" {
+            durations
+                .entry((record.library, record.category))
+                .or_default()
+                .push(record.duration_us);
+        }
+    }
+    Ok(durations)
+}
+
+fn median(durations: &[u64]) -> f64 {
+    let mut sorted = durations.to_vec();
+    sorted.sort();
+    let n = sorted.len();
+    if n % 2 == 0 {
+        (sorted[n / 2 - 1] + sorted[n / 2]) as f64 / 2.0
+    } else {
+        sorted[n / 2] as f64
+    }
+}
+
+const PERMUTATIONS: usize = 2000;
+const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// Permutation test on the pooled (baseline, current) samples for one
+/// group: shuffle the combined sample labels `PERMUTATIONS` times and
+/// count how often the resampled median difference is at least as extreme
+/// as the one actually observed. `p < 0.05` is treated as significant.
+fn permutation_test_p_value(baseline: &[u64], current: &[u64], observed_diff: f64) -> f64 {
+    let mut pooled: Vec<u64> = baseline.iter().chain(current.iter()).cloned().collect();
+    let mut rng = rand::thread_rng();
+    let mut at_least_as_extreme = 0usize;
+
+    for _ in 0..PERMUTATIONS {
+        pooled.shuffle(&mut rng);
+        let (shuffled_baseline, shuffled_current) = pooled.split_at(baseline.len());
+        let resampled_diff = median(shuffled_current) - median(shuffled_baseline);
+        if resampled_diff.abs() >= observed_diff.abs() {
+            at_least_as_extreme += 1;
+        }
+    }
+
+    at_least_as_extreme as f64 / PERMUTATIONS as f64
+}
+
+pub struct RegressionResult {
+    pub library: String,
+    pub category: String,
+    pub baseline_median_us: f64,
+    pub current_median_us: f64,
+    pub relative_change: f64,
+    pub p_value: f64,
+    pub significant: bool,
+}
+
+/// Compare a saved baseline measurement CSV against a current run and
+/// produce a per-(library, category) regression report.
+pub fn compare_baseline(baseline_csv: &str, current_csv: &str) -> Result<Vec<RegressionResult>> {
+    let baseline_durations = load_correct_durations_by_group(baseline_csv)?;
+    let current_durations = load_correct_durations_by_group(current_csv)?;
+
+    let mut results = Vec::new();
+    let mut groups: Vec<&(String, String)> = baseline_durations
+        .keys()
+        .filter(|k| current_durations.contains_key(*k))
+        .collect();
+    groups.sort();
+
+    for group in groups {
+        let baseline = &baseline_durations[group];
+        let current = &current_durations[group];
+
+        let baseline_median = median(baseline);
+        let current_median = median(current);
+        let observed_diff = current_median - baseline_median;
+        let relative_change = if baseline_median == 0.0 {
+            0.0
+        } else {
+            observed_diff / baseline_median
+        };
+
+        let p_value = permutation_test_p_value(baseline, current, observed_diff);
+
+        results.push(RegressionResult {
+            library: group.0.clone(),
+            category: group.1.clone(),
+            baseline_median_us: baseline_median,
+            current_median_us: current_median,
+            relative_change,
+            p_value,
+            significant: p_value < SIGNIFICANCE_LEVEL,
+        });
+    }
+
+    draw_regression_report(&results)?;
+
+    Ok(results)
+}
+
+fn draw_regression_report(results: &[RegressionResult]) -> Result<()> {
+    if results.is_empty() {
+        return Ok(());
+    }
+
+    let root = BitMapBackend::new("comparison.png", (1280, 800)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let labels: Vec<String> = results
+        .iter()
+        .map(|r| format!("{}/{}", r.library, r.category))
+        .collect();
+
+    let max_abs_change = results
+        .iter()
+        .map(|r| r.relative_change.abs())
+        .fold(0.0_f64, f64::max)
+        .max(0.05);
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Baseline Comparison (% change in median duration)", ("sans-serif", 30))
+        .margin(20)
+        .x_label_area_size(80)
+        .y_label_area_size(60)
+        .build_cartesian_2d(
+            0.0..results.len() as f64,
+            -max_abs_change * 1.2..max_abs_change * 1.2,
+        )?;
+
+    chart
+        .configure_mesh()
+        .x_labels(labels.len())
+        .x_label_formatter(&|x| {
+            let idx = x.floor() as usize;
+            labels.get(idx).cloned().unwrap_or_default()
+        })
+        .y_label_formatter(&|y| format!("{:.0}%", y * 100.0))
+        .y_desc("Change vs baseline")
+        .draw()?;
+
+    let bar_width = 0.6;
+    for (idx, result) in results.iter().enumerate() {
+        let center = idx as f64 + 0.5;
+        let x0 = center - bar_width / 2.0;
+        let x1 = center + bar_width / 2.0;
+
+        let color = if !result.significant {
+            RGBColor(150, 150, 150)
+        } else if result.relative_change < 0.0 {
+            GREEN // faster
+        } else {
+            RED // slower
+        };
+
+        chart.draw_series(std::iter::once(Rectangle::new(
+            [(x0, 0.0), (x1, result.relative_change)],
+            color.filled(),
+        )))?;
+    }
+
+    Ok(())
+}